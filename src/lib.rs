@@ -1,6 +1,11 @@
 #![deny(clippy::use_self)]
 
-use std::{marker::PhantomData, num::NonZeroU32};
+use std::{
+    marker::PhantomData,
+    mem::MaybeUninit,
+    num::NonZeroU32,
+    sync::atomic::{AtomicPtr, AtomicU32, Ordering},
+};
 
 #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 mod size_asserts {
@@ -15,24 +20,51 @@ pub struct List<T> {
 }
 
 pub struct Node<T> {
-    head: T,
+    head: MaybeUninit<T>,
     tail: ListNodePtr<T>,
+    generation: u8,
+}
+
+impl<T> Node<T> {
+    /// # Safety
+    /// Only valid while this slot hasn't been freed since its `generation`
+    /// was checked against a live `ListNodePtr`, i.e. right after a call to
+    /// `data`.
+    fn value(&self) -> &T {
+        unsafe { self.head.assume_init_ref() }
+    }
 }
 
 impl<T> List<T> {
     pub fn peek<'a>(&self, arena: &'a Arena<T>) -> Option<&'a T> {
-        (self.node != ListNodePtr::INVALID).then(|| &arena.data(self.node).head)
+        (self.node != ListNodePtr::INVALID).then(|| arena.data(self.node).value())
     }
 
     pub fn is_empty(&self) -> bool {
         self.node == ListNodePtr::INVALID
     }
 
+    /// Pushes `head` onto the front of the list, growing the arena if
+    /// needed.
+    ///
+    /// # Panics
+    /// Panics if the arena's `ListNodePtr` index space is exhausted. Use
+    /// [`Self::try_push_front`] to handle that case instead.
     pub fn push_front(&mut self, arena: &mut Arena<T>, head: T) {
-        self.node = arena.add(Node {
-            head,
-            tail: self.node,
-        });
+        if let Err(err) = self.try_push_front(arena, head) {
+            panic!("{err}");
+        }
+    }
+
+    /// Fallible version of [`Self::push_front`]: on failure, the error
+    /// carries `head` back so the caller doesn't lose it.
+    pub fn try_push_front(
+        &mut self,
+        arena: &mut Arena<T>,
+        head: T,
+    ) -> Result<(), TryPushError<T>> {
+        self.node = arena.try_add(head, self.node)?;
+        Ok(())
     }
 
     pub fn pop_front<'a>(&mut self, arena: &'a Arena<T>) -> Option<&'a T> {
@@ -42,12 +74,85 @@ impl<T> List<T> {
 
         let node = arena.data(self.node);
         self.node = node.tail;
-        Some(&node.head)
+        Some(node.value())
+    }
+
+    /// Like [`Self::pop_front`], but takes ownership of the popped value and
+    /// returns its slot to the arena's free list.
+    ///
+    /// Only call this when the caller knows `self` is the only `List<T>`
+    /// that still reaches this node: other `List<T>` values sharing this
+    /// tail would otherwise be left pointing at a freed (and possibly
+    /// reused) slot.
+    pub fn pop_front_owned(&mut self, arena: &mut Arena<T>) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let ptr = self.node;
+        self.node = arena.data(ptr).tail;
+        Some(arena.free(ptr))
     }
 
     pub fn iter(mut self, arena: &Arena<T>) -> impl Iterator<Item = &T> {
         std::iter::from_fn(move || self.pop_front(arena))
     }
+
+    /// Builds a list with `self`'s elements in reverse order, leaving
+    /// `self` untouched.
+    pub fn reverse(self, arena: &mut Arena<T>) -> Self
+    where
+        T: Clone,
+    {
+        let values: Vec<T> = self.iter(&*arena).cloned().collect();
+
+        let mut reversed = Self::default();
+        for value in values {
+            reversed.push_front(arena, value);
+        }
+        reversed
+    }
+
+    /// Builds a list whose elements are `self`'s followed by `other`'s,
+    /// both in their original order. Copies `self`'s nodes onto `other`;
+    /// `other` itself is shared, not copied.
+    pub fn append(self, other: Self, arena: &mut Arena<T>) -> Self
+    where
+        T: Clone,
+    {
+        let values: Vec<T> = self.iter(&*arena).cloned().collect();
+
+        let mut result = other;
+        for value in values.into_iter().rev() {
+            result.push_front(arena, value);
+        }
+        result
+    }
+
+    /// Pushes every item of `iter` onto the front of the list, in the
+    /// iterator's order (so the last item pushed ends up at the front).
+    pub fn prepend_iter<I>(&mut self, arena: &mut Arena<T>, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for value in iter {
+            self.push_front(arena, value);
+        }
+    }
+
+    /// Builds a list from an iterator, preserving the iterator's order.
+    pub fn from_iter<I>(arena: &mut Arena<T>, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let values: Vec<T> = iter.into_iter().collect();
+
+        let mut list = Self::default();
+        for value in values.into_iter().rev() {
+            list.push_front(arena, value);
+        }
+        list
+    }
 }
 
 impl<T> Clone for List<T> {
@@ -65,27 +170,315 @@ impl<T> Default for List<T> {
     }
 }
 
+/// Error returned by [`List::try_push_front`], carrying back the `head`
+/// value that couldn't be pushed so the caller doesn't lose it.
+pub struct TryPushError<T> {
+    pub head: T,
+    kind: TryPushErrorKind,
+}
+
+impl<T> TryPushError<T> {
+    pub fn kind(&self) -> TryPushErrorKind {
+        self.kind
+    }
+}
+
+impl<T> std::fmt::Debug for TryPushError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TryPushError").field("kind", &self.kind).finish()
+    }
+}
+
+impl<T> std::fmt::Display for TryPushError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            TryPushErrorKind::CapacityExhausted => {
+                write!(f, "arena capacity exhausted: no ListNodePtr slots remain")
+            }
+        }
+    }
+}
+
+impl<T> std::error::Error for TryPushError<T> {}
+
+/// Distinguishes why a [`TryPushError`] occurred. `#[non_exhaustive]` so a
+/// future allocation-failure variant (e.g. if `Arena` grows a fallible
+/// allocation path) can be added without a breaking change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TryPushErrorKind {
+    /// `Arena`'s `ListNodePtr` index space (`ListNodePtr::MAX_USIZE - 1`
+    /// slots) is exhausted.
+    CapacityExhausted,
+}
+
+/// Backing store for `Node<T>`, organized as geometrically growing chunks
+/// (as in `rustc_arena`) rather than one contiguously reallocated `Vec`, so
+/// that growing the arena never moves already-allocated nodes and never
+/// memcpys the whole buffer.
 pub struct Arena<T> {
-    values: Vec<Node<T>>,
+    chunks: Vec<Box<[MaybeUninit<Node<T>>]>>,
+    len: usize,
+    free_head: ListNodePtr<T>,
+}
+
+/// Slot count of chunk 0; chunk `c` holds `FIRST_CHUNK_LEN << c` slots. Both
+/// `Arena` and `SyncArena` lay their chunks out this way so the same
+/// index arithmetic works for either.
+const FIRST_CHUNK_LEN: usize = 4;
+
+/// Maps a 0-based slot index to `(chunk, offset)` arithmetically. Chunk `c`
+/// starts right after the slots of all earlier chunks, so for
+/// `n = index / FIRST_CHUNK_LEN + 1` the chunk is `n`'s bit length minus one.
+fn chunk_layout(index: usize) -> (usize, usize) {
+    let n = index / FIRST_CHUNK_LEN + 1;
+    let chunk = (usize::BITS - 1 - n.leading_zeros()) as usize;
+    let chunk_start = FIRST_CHUNK_LEN * ((1usize << chunk) - 1);
+    (chunk, index - chunk_start)
+}
+
+fn chunk_capacity(chunk: usize) -> usize {
+    FIRST_CHUNK_LEN << chunk
 }
 
 impl<T> Arena<T> {
-    fn add(&mut self, t: Node<T>) -> ListNodePtr<T> {
-        self.values.push(t);
+    fn slot(chunks: &[Box<[MaybeUninit<Node<T>>]>], index: usize) -> &MaybeUninit<Node<T>> {
+        let (chunk, offset) = chunk_layout(index);
+        &chunks[chunk][offset]
+    }
+
+    fn slot_mut(
+        chunks: &mut [Box<[MaybeUninit<Node<T>>]>],
+        index: usize,
+    ) -> &mut MaybeUninit<Node<T>> {
+        let (chunk, offset) = chunk_layout(index);
+        &mut chunks[chunk][offset]
+    }
 
-        let len = self.values.len();
-        assert!(len < ListNodePtr::<T>::MAX_USIZE);
-        unsafe { ListNodePtr::new_unchecked(len as u32) }
+    /// Allocates the chunk that will hold `index` if it doesn't exist yet,
+    /// doubling the chunk size each time and capping it so the global index
+    /// never grows past `ListNodePtr::MAX_USIZE`.
+    fn reserve(chunks: &mut Vec<Box<[MaybeUninit<Node<T>>]>>, index: usize) {
+        let (chunk, _) = chunk_layout(index);
+        if chunk == chunks.len() {
+            let cap = chunk_capacity(chunk).min(ListNodePtr::<T>::MAX_USIZE - index);
+            chunks.push(std::iter::repeat_with(MaybeUninit::uninit).take(cap).collect());
+        }
+    }
+
+    fn try_add(&mut self, head: T, tail: ListNodePtr<T>) -> Result<ListNodePtr<T>, TryPushError<T>> {
+        if self.free_head != ListNodePtr::INVALID {
+            let ptr = self.free_head;
+            let idx = ptr.as_usize();
+            self.free_head = unsafe { Self::slot(&self.chunks, idx).assume_init_ref().tail };
+            *Self::slot_mut(&mut self.chunks, idx) = MaybeUninit::new(Node {
+                head: MaybeUninit::new(head),
+                tail,
+                generation: ptr.generation(),
+            });
+            return Ok(ptr);
+        }
+
+        let index = self.len;
+        // `index + 1` must stay below `ListNodePtr::MAX_U32` (not just
+        // below it inclusive), or the last permitted index would pack to
+        // the same bits as `ListNodePtr::INVALID`.
+        if index >= ListNodePtr::<T>::MAX_USIZE - 1 {
+            return Err(TryPushError {
+                head,
+                kind: TryPushErrorKind::CapacityExhausted,
+            });
+        }
+        Self::reserve(&mut self.chunks, index);
+        *Self::slot_mut(&mut self.chunks, index) = MaybeUninit::new(Node {
+            head: MaybeUninit::new(head),
+            tail,
+            generation: 0,
+        });
+        self.len += 1;
+
+        Ok(unsafe { ListNodePtr::new_unchecked((index + 1) as u32, 0) })
     }
 
     fn data(&self, ptr: ListNodePtr<T>) -> &Node<T> {
-        &self.values[ptr.as_usize()]
+        // SAFETY: every index below `self.len` has been written by `try_add`.
+        let node = unsafe { Self::slot(&self.chunks, ptr.as_usize()).assume_init_ref() };
+        debug_assert_eq!(
+            node.generation,
+            ptr.generation(),
+            "use-after-free: ListNodePtr refers to a slot that has since been freed and reused"
+        );
+        node
+    }
+
+    /// Takes ownership of the slot's value and threads it onto the free
+    /// list (through `Node::tail`, which is otherwise unused once a slot is
+    /// free) so a later `try_add` can reuse it instead of growing the arena.
+    ///
+    /// Bumps the slot's generation so any other `ListNodePtr` still
+    /// pointing at it is caught by the `debug_assert` in `data` rather than
+    /// silently aliasing whatever gets written into the reused slot.
+    fn free(&mut self, ptr: ListNodePtr<T>) -> T {
+        let idx = ptr.as_usize();
+        let node = unsafe { Self::slot(&self.chunks, idx).assume_init_ref() };
+        debug_assert_eq!(
+            node.generation,
+            ptr.generation(),
+            "double free: ListNodePtr was already freed"
+        );
+
+        // SAFETY: the generation check above confirms this slot is live.
+        let value = unsafe { node.head.assume_init_read() };
+        let generation = node.generation.wrapping_add(1);
+
+        *Self::slot_mut(&mut self.chunks, idx) = MaybeUninit::new(Node {
+            head: MaybeUninit::uninit(),
+            tail: self.free_head,
+            generation,
+        });
+        self.free_head = unsafe { ListNodePtr::new_unchecked(ptr.raw_index(), generation) };
+
+        value
+    }
+
+    /// Compacting collector: keeps only the nodes reachable from `roots`,
+    /// discards everything else (including the slots on the arena's own
+    /// free list, which is emptied), and returns the number of slots
+    /// reclaimed.
+    ///
+    /// This is a classic mark-and-copy collector over the arena graph: mark
+    /// walks every root's `node`/`tail` chain, then copy-compact moves live
+    /// nodes into a fresh contiguous region and builds a forwarding map from
+    /// old `ListNodePtr` to new, which is then used to rewrite every root
+    /// and every surviving node's `tail`. `ListNodePtr::INVALID` always maps
+    /// to itself. Mark stops at the first already-marked node, so tails
+    /// shared by multiple roots are marked once, and copy-compact walks the
+    /// old indices in order, so each live node is copied exactly once.
+    pub fn collect(&mut self, roots: &mut [&mut List<T>]) -> usize {
+        let old_len = self.len;
+        let mut live = vec![false; old_len];
+        let mut on_free_list = vec![false; old_len];
+
+        for root in roots.iter() {
+            let mut node = root.node;
+            while node != ListNodePtr::INVALID {
+                let idx = node.as_usize();
+                if live[idx] {
+                    break;
+                }
+                live[idx] = true;
+                node = self.data(node).tail;
+            }
+        }
+
+        {
+            let mut node = self.free_head;
+            while node != ListNodePtr::INVALID {
+                let idx = node.as_usize();
+                if on_free_list[idx] {
+                    break;
+                }
+                on_free_list[idx] = true;
+                node = self.data(node).tail;
+            }
+        }
+
+        // Anything neither reachable from a root nor on the free list was
+        // abandoned by a `List<T>` that was simply dropped rather than
+        // popped empty. Its value is still live and must be dropped here,
+        // or it would be silently leaked once its chunk is discarded below.
+        for (idx, (&is_live, &is_free)) in live.iter().zip(&on_free_list).enumerate() {
+            if !is_live && !is_free {
+                unsafe {
+                    Self::slot_mut(&mut self.chunks, idx)
+                        .assume_init_mut()
+                        .head
+                        .assume_init_drop();
+                }
+            }
+        }
+
+        let mut forward = vec![ListNodePtr::INVALID; old_len];
+        let mut new_len = 0usize;
+        for (idx, is_live) in live.iter().enumerate() {
+            if *is_live {
+                forward[idx] = unsafe { ListNodePtr::new_unchecked((new_len + 1) as u32, 0) };
+                new_len += 1;
+            }
+        }
+        let map = |ptr: ListNodePtr<T>| {
+            if ptr == ListNodePtr::INVALID {
+                ptr
+            } else {
+                forward[ptr.as_usize()]
+            }
+        };
+
+        let mut new_chunks = Vec::new();
+        for (old_idx, &new_ptr) in forward.iter().enumerate() {
+            if !live[old_idx] {
+                continue;
+            }
+            let new_idx = new_ptr.as_usize();
+            Self::reserve(&mut new_chunks, new_idx);
+
+            // SAFETY: `old_idx` is live, so `try_add` initialized both the node
+            // and its `head`, and we only ever copy each old index once.
+            let old_node = unsafe { Self::slot(&self.chunks, old_idx).assume_init_read() };
+            let head = unsafe { old_node.head.assume_init_read() };
+
+            *Self::slot_mut(&mut new_chunks, new_idx) = MaybeUninit::new(Node {
+                head: MaybeUninit::new(head),
+                tail: map(old_node.tail),
+                generation: 0,
+            });
+        }
+
+        for root in roots {
+            root.node = map(root.node);
+        }
+
+        self.chunks = new_chunks;
+        self.len = new_len;
+        self.free_head = ListNodePtr::INVALID;
+
+        old_len - new_len
     }
 }
 
 impl<T> Default for Arena<T> {
     fn default() -> Self {
-        Self { values: Vec::new() }
+        Self {
+            chunks: Vec::new(),
+            len: 0,
+            free_head: ListNodePtr::INVALID,
+        }
+    }
+}
+
+impl<T> Drop for Arena<T> {
+    fn drop(&mut self) {
+        let mut freed = vec![false; self.len];
+
+        let mut node = self.free_head;
+        while node != ListNodePtr::INVALID {
+            let idx = node.as_usize();
+            if freed[idx] {
+                break;
+            }
+            freed[idx] = true;
+            node = unsafe { Self::slot(&self.chunks, idx).assume_init_ref().tail };
+        }
+
+        // Anything not on the free list is still live and must be dropped,
+        // same as `collect`'s handling of abandoned nodes.
+        for (idx, &is_freed) in freed.iter().enumerate() {
+            if !is_freed {
+                unsafe {
+                    Self::slot_mut(&mut self.chunks, idx).assume_init_mut().head.assume_init_drop();
+                }
+            }
+        }
     }
 }
 
@@ -113,19 +506,353 @@ impl<T> PartialEq for ListNodePtr<T> {
 impl<T> Eq for ListNodePtr<T> {}
 
 impl<T> ListNodePtr<T> {
-    const INVALID: Self = unsafe { Self::new_unchecked(Self::MAX_U32) };
-    const MAX_U32: u32 = std::u32::MAX - 0xFF;
+    /// Slot index occupies the low 24 bits, leaving the high 8 bits for the
+    /// generation counter that guards against use-after-free/ABA once slots
+    /// are recycled through `Arena`'s free list.
+    const INDEX_BITS: u32 = 24;
+    const INDEX_MASK: u32 = (1 << Self::INDEX_BITS) - 1;
+
+    const INVALID: Self = unsafe { Self::new_unchecked(Self::MAX_U32, 0) };
+    const MAX_U32: u32 = Self::INDEX_MASK;
     const MAX_USIZE: usize = Self::MAX_U32 as usize;
 
-    const unsafe fn new_unchecked(index: u32) -> Self {
+    /// `index` is the 1-based slot index (as stored in the low 24 bits,
+    /// i.e. the value already used directly as the non-zero packed index).
+    const unsafe fn new_unchecked(index: u32, generation: u8) -> Self {
         Self {
-            index: NonZeroU32::new_unchecked(index),
+            index: NonZeroU32::new_unchecked(((generation as u32) << Self::INDEX_BITS) | index),
             marker: PhantomData,
         }
     }
 
     fn as_usize(self) -> usize {
-        (self.index.get() - 1) as usize
+        ((self.index.get() & Self::INDEX_MASK) - 1) as usize
+    }
+
+    /// The packed 1-based index bits, with the generation masked out —
+    /// enough to reconstruct a pointer into the same slot with a new
+    /// generation.
+    fn raw_index(self) -> u32 {
+        self.index.get() & Self::INDEX_MASK
+    }
+
+    fn generation(self) -> u8 {
+        (self.index.get() >> Self::INDEX_BITS) as u8
+    }
+
+    /// The full packed (index, generation) representation, suitable for
+    /// storing in an `AtomicU32` — e.g. `SyncArena`'s free list.
+    fn raw(self) -> u32 {
+        self.index.get()
+    }
+
+    /// # Safety
+    /// `raw` must be a value previously returned by `Self::raw`.
+    const unsafe fn from_raw(raw: u32) -> Self {
+        Self {
+            index: NonZeroU32::new_unchecked(raw),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Upper bound on the number of chunks `SyncArena` will ever allocate.
+/// `FIRST_CHUNK_LEN << (SYNC_MAX_CHUNKS - 1)` comfortably covers
+/// `ListNodePtr::MAX_USIZE`, so this is never a real limit in practice —
+/// just a fixed-size table to avoid a lock-free growable chunk list.
+const SYNC_MAX_CHUNKS: usize = 24;
+
+/// Lock-free counterpart to `Arena`: `push_front` and `free` take `&self`,
+/// so many threads can build independent `List<T>` values into one shared
+/// arena concurrently, while readers `peek`/iterate without synchronizing
+/// with writers at all (chunks are never moved or freed once published).
+///
+/// New nodes are handed out by a CAS-based free list (as in heapless's
+/// lock-free `Pool`) falling back to an atomic bump allocator, and chunks
+/// grow the same geometrically-sized way as `Arena`'s, published through a
+/// CAS on each chunk's pointer so at most one allocation per chunk survives.
+/// `List<T>` handles are plain `Copy` indices, so they're valid to pass to
+/// any thread sharing this arena.
+pub struct SyncArena<T> {
+    chunks: [AtomicPtr<SyncSlot<T>>; SYNC_MAX_CHUNKS],
+    len: AtomicU32,
+    free_head: AtomicU32,
+}
+
+/// One `SyncArena` slot: `node`'s `tail` field is the live-list link, used
+/// exactly as `Arena`'s `Node::tail` is. The free list's "next free slot"
+/// link is a *separate* `AtomicU32`, not `node.tail`, so that a thread
+/// reading it while racing another thread's `compare_exchange_weak` on
+/// `free_head` is always an atomic load — never a plain read racing against
+/// the plain store that republishes `node` for a new live value.
+struct SyncSlot<T> {
+    node: MaybeUninit<Node<T>>,
+    free_next: AtomicU32,
+}
+
+// SAFETY: `push_front` fully initializes a `Node<T>` before returning its
+// `ListNodePtr`, and a `List<T>` holding that pointer only reaches another
+// thread through whatever synchronization hands it over, which orders the
+// write before that thread's reads. The Release/Acquire pairs on `chunks`
+// and `free_head` order chunk allocation and slot reuse between threads.
+// `peek`/`data` hand out `&T` to any thread holding `&SyncArena<T>`, and
+// `push_front`/`free` move a `T` in on one thread and out on another, so
+// `Sync` requires `T: Send + Sync`; moving the whole arena across threads
+// only requires `T: Send`, same as `Arena<T>`.
+unsafe impl<T: Send + Sync> Sync for SyncArena<T> {}
+unsafe impl<T: Send> Send for SyncArena<T> {}
+
+impl<T> SyncArena<T> {
+    fn slot(&self, index: usize) -> *mut SyncSlot<T> {
+        let (chunk, offset) = chunk_layout(index);
+        let base = self.chunks[chunk].load(Ordering::Acquire);
+        debug_assert!(!base.is_null(), "index was never allocated");
+        unsafe { base.add(offset) }
+    }
+
+    /// Allocates the chunk containing `index` if no thread has published one
+    /// yet, via a CAS on that chunk's pointer. The loser of a race reclaims
+    /// its redundant allocation instead of leaking it.
+    fn ensure_chunk_and_slot(&self, index: usize) -> *mut SyncSlot<T> {
+        let (chunk, offset) = chunk_layout(index);
+        assert!(chunk < SYNC_MAX_CHUNKS, "SyncArena exhausted its chunk table");
+
+        let cell = &self.chunks[chunk];
+        let mut base = cell.load(Ordering::Acquire);
+        if base.is_null() {
+            let cap = chunk_capacity(chunk);
+            let boxed: Box<[SyncSlot<T>]> = std::iter::repeat_with(|| SyncSlot {
+                node: MaybeUninit::uninit(),
+                free_next: AtomicU32::new(ListNodePtr::<T>::INVALID.raw()),
+            })
+            .take(cap)
+            .collect();
+            let fresh = Box::into_raw(boxed) as *mut SyncSlot<T>;
+
+            base = match cell.compare_exchange(
+                std::ptr::null_mut(),
+                fresh,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => fresh,
+                Err(published) => {
+                    // SAFETY: the CAS that would have published `fresh`
+                    // failed, so no other thread ever observed it.
+                    unsafe {
+                        drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(fresh, cap)));
+                    }
+                    published
+                }
+            };
+        }
+
+        unsafe { base.add(offset) }
+    }
+
+    /// Pushes `head` in front of `tail`, returning the new node's pointer.
+    ///
+    /// Tries the free list first (a CAS retry loop popping `free_head`),
+    /// falling back to an atomic bump allocation of a fresh slot when the
+    /// free list is empty.
+    fn push_front(&self, tail: ListNodePtr<T>, head: T) -> ListNodePtr<T> {
+        loop {
+            let raw = self.free_head.load(Ordering::Acquire);
+            if raw == ListNodePtr::<T>::INVALID.raw() {
+                break;
+            }
+
+            let ptr = unsafe { ListNodePtr::from_raw(raw) };
+            let slot = self.slot(ptr.as_usize());
+            // SAFETY: `free_next` is a dedicated atomic distinct from
+            // `node`, so this load never races with another thread's plain
+            // write to `node` below after that thread wins the slot (see
+            // `SyncSlot`'s doc comment).
+            let next = unsafe { (*slot).free_next.load(Ordering::Acquire) };
+
+            if self
+                .free_head
+                .compare_exchange_weak(raw, next, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                unsafe {
+                    (*slot).node = MaybeUninit::new(Node {
+                        head: MaybeUninit::new(head),
+                        tail,
+                        generation: ptr.generation(),
+                    });
+                }
+                return ptr;
+            }
+        }
+
+        let index = self.len.fetch_add(1, Ordering::AcqRel) as usize;
+        // `index + 1` must stay below `ListNodePtr::MAX_U32` (not just below
+        // it inclusive), or the last permitted index would pack to the same
+        // bits as `ListNodePtr::INVALID`.
+        assert!(
+            index < ListNodePtr::<T>::MAX_USIZE - 1,
+            "SyncArena exhausted its ListNodePtr index space"
+        );
+
+        let slot = self.ensure_chunk_and_slot(index);
+        unsafe {
+            (*slot).node = MaybeUninit::new(Node {
+                head: MaybeUninit::new(head),
+                tail,
+                generation: 0,
+            });
+        }
+
+        unsafe { ListNodePtr::new_unchecked((index + 1) as u32, 0) }
+    }
+
+    fn data(&self, ptr: ListNodePtr<T>) -> &Node<T> {
+        // SAFETY: `ptr` was returned by a previous `push_front`, which wrote
+        // its node before returning it (see the `Sync` impl above); the
+        // Acquire load in `slot` synchronizes with the chunk's publication.
+        let node = unsafe { (*self.slot(ptr.as_usize())).node.assume_init_ref() };
+        debug_assert_eq!(
+            node.generation,
+            ptr.generation(),
+            "use-after-free: ListNodePtr refers to a slot that has since been freed and reused"
+        );
+        node
+    }
+
+    fn peek(&self, ptr: ListNodePtr<T>) -> Option<&T> {
+        (ptr != ListNodePtr::INVALID).then(|| self.data(ptr).value())
+    }
+
+    /// Takes ownership of the slot's value and threads it onto the
+    /// CAS-based free list (via its dedicated `free_next` atomic, not
+    /// `node.tail` — see `SyncSlot`'s doc comment) so a later `push_front`
+    /// can reuse it.
+    ///
+    /// As with `Arena::free`, only call this when no other `List<T>` still
+    /// reaches this node.
+    fn free(&self, ptr: ListNodePtr<T>) -> T {
+        let idx = ptr.as_usize();
+        let slot = self.slot(idx);
+        // SAFETY: the generation check below confirms this slot is live.
+        let node = unsafe { (*slot).node.assume_init_ref() };
+        debug_assert_eq!(
+            node.generation,
+            ptr.generation(),
+            "double free: ListNodePtr was already freed"
+        );
+
+        let value = unsafe { node.head.assume_init_read() };
+        let generation = node.generation.wrapping_add(1);
+
+        // SAFETY: the caller guarantees no other `List<T>` still reaches
+        // this node, so nothing else reads/writes `node` concurrently here.
+        unsafe {
+            (*slot).node = MaybeUninit::new(Node {
+                head: MaybeUninit::uninit(),
+                tail: ListNodePtr::INVALID,
+                generation,
+            });
+        }
+
+        loop {
+            let next_raw = self.free_head.load(Ordering::Acquire);
+            unsafe { (*slot).free_next.store(next_raw, Ordering::Release) };
+
+            let new_head = unsafe { ListNodePtr::<T>::new_unchecked(ptr.raw_index(), generation) };
+            if self
+                .free_head
+                .compare_exchange_weak(next_raw, new_head.raw(), Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return value;
+            }
+        }
+    }
+}
+
+impl<T> Default for SyncArena<T> {
+    fn default() -> Self {
+        Self {
+            chunks: std::array::from_fn(|_| AtomicPtr::new(std::ptr::null_mut())),
+            len: AtomicU32::new(0),
+            free_head: AtomicU32::new(ListNodePtr::<T>::INVALID.raw()),
+        }
+    }
+}
+
+impl<T> Drop for SyncArena<T> {
+    fn drop(&mut self) {
+        let len = *self.len.get_mut() as usize;
+        let mut freed = vec![false; len];
+
+        let mut raw = *self.free_head.get_mut();
+        while raw != ListNodePtr::<T>::INVALID.raw() {
+            let ptr = unsafe { ListNodePtr::<T>::from_raw(raw) };
+            let idx = ptr.as_usize();
+            if freed[idx] {
+                break;
+            }
+            freed[idx] = true;
+            // SAFETY: `&mut self` means no other thread can be touching
+            // this arena concurrently.
+            raw = unsafe { *(*self.slot(idx)).free_next.get_mut() };
+        }
+
+        // Anything not on the free list is still live and must be dropped,
+        // same as `Arena::collect`'s handling of abandoned nodes.
+        for (idx, &is_freed) in freed.iter().enumerate() {
+            if !is_freed {
+                unsafe {
+                    (*self.slot(idx)).node.assume_init_mut().head.assume_init_drop();
+                }
+            }
+        }
+
+        for (chunk, cell) in self.chunks.iter_mut().enumerate() {
+            let base = *cell.get_mut();
+            if !base.is_null() {
+                let cap = chunk_capacity(chunk);
+                unsafe {
+                    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(base, cap)));
+                }
+            }
+        }
+    }
+}
+
+impl<T> List<T> {
+    pub fn push_front_sync(&mut self, arena: &SyncArena<T>, head: T) {
+        self.node = arena.push_front(self.node, head);
+    }
+
+    pub fn peek_sync<'a>(&self, arena: &'a SyncArena<T>) -> Option<&'a T> {
+        arena.peek(self.node)
+    }
+
+    pub fn iter_sync(mut self, arena: &SyncArena<T>) -> impl Iterator<Item = &T> {
+        std::iter::from_fn(move || {
+            let value = arena.peek(self.node)?;
+            self.node = arena.data(self.node).tail;
+            Some(value)
+        })
+    }
+
+    /// Like [`Self::pop_front_owned`], but against a [`SyncArena`]: takes
+    /// ownership of the popped value and threads its slot onto the arena's
+    /// CAS-based free list so a later `push_front_sync` (on any thread) can
+    /// reuse it.
+    ///
+    /// Only call this when the caller knows `self` is the only `List<T>`
+    /// that still reaches this node.
+    pub fn pop_front_owned_sync(&mut self, arena: &SyncArena<T>) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let ptr = self.node;
+        self.node = arena.data(ptr).tail;
+        Some(arena.free(ptr))
     }
 }
 
@@ -138,7 +865,7 @@ macro_rules! static_assert_size {
 
 #[cfg(test)]
 mod test {
-    use super::{Arena, List};
+    use super::{Arena, List, SyncArena, TryPushError, TryPushErrorKind};
 
     #[test]
     fn basics() {
@@ -178,4 +905,230 @@ mod test {
         assert_eq!(list.pop_front(&arena), Some(&1));
         assert_eq!(list.pop_front(&arena), None);
     }
+
+    #[test]
+    fn pop_front_owned_recycles_slots() {
+        let mut arena = Arena::default();
+        let mut list = List::default();
+
+        list.push_front(&mut arena, 1);
+        list.push_front(&mut arena, 2);
+
+        // Popping ownership should free the slots rather than leaking them.
+        assert_eq!(list.pop_front_owned(&mut arena), Some(2));
+        assert_eq!(list.pop_front_owned(&mut arena), Some(1));
+        assert_eq!(list.pop_front_owned(&mut arena), None);
+
+        // A later push should reuse the freed slots instead of growing the
+        // arena, and reads through the new list must not see stale data.
+        list.push_front(&mut arena, 3);
+        list.push_front(&mut arena, 4);
+        assert_eq!(arena.len, 2);
+        assert_eq!(list.iter(&arena).copied().collect::<Vec<_>>(), &[4, 3]);
+    }
+
+    #[test]
+    fn grows_across_many_chunks() {
+        let mut arena = Arena::default();
+        let mut list = List::default();
+
+        // Comfortably spans several chunk doublings past `FIRST_CHUNK_LEN`.
+        let n = 200;
+        for i in 0..n {
+            list.push_front(&mut arena, i);
+        }
+
+        let expected: Vec<_> = (0..n).rev().collect();
+        assert_eq!(list.iter(&arena).copied().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn collect_keeps_only_reachable_nodes() {
+        let mut arena = Arena::default();
+
+        // `shared` is the tail of both `a` and `b`.
+        let mut shared = List::default();
+        shared.push_front(&mut arena, 1);
+        shared.push_front(&mut arena, 2);
+
+        let mut a = shared;
+        a.push_front(&mut arena, 3);
+
+        let mut b = shared;
+        b.push_front(&mut arena, 4);
+
+        // Abandoned without popping: collect must still reclaim its slot.
+        let mut orphan = shared;
+        orphan.push_front(&mut arena, 5);
+
+        let reclaimed = arena.collect(&mut [&mut a, &mut b]);
+        assert_eq!(reclaimed, 1);
+
+        assert_eq!(a.iter(&arena).copied().collect::<Vec<_>>(), &[3, 2, 1]);
+        assert_eq!(b.iter(&arena).copied().collect::<Vec<_>>(), &[4, 2, 1]);
+    }
+
+    #[test]
+    fn collect_drops_abandoned_values() {
+        use std::rc::Rc;
+
+        let mut arena = Arena::default();
+        let mut list = List::default();
+        let counter = Rc::new(());
+
+        list.push_front(&mut arena, Rc::clone(&counter));
+        list.push_front(&mut arena, Rc::clone(&counter));
+        assert_eq!(Rc::strong_count(&counter), 3);
+
+        // `list` is simply never passed as a root below, so it's unreachable.
+        let _ = list;
+        arena.collect(&mut []);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn try_push_front_matches_push_front() {
+        let mut arena = Arena::default();
+        let mut list = List::default();
+
+        assert!(list.try_push_front(&mut arena, 1).is_ok());
+        list.push_front(&mut arena, 2);
+
+        assert_eq!(list.iter(&arena).copied().collect::<Vec<_>>(), &[2, 1]);
+    }
+
+    #[test]
+    fn try_push_error_head_and_kind_round_trip() {
+        // Actually exhausting `ListNodePtr::MAX_USIZE` slots isn't practical
+        // in a unit test, so this drives `TryPushError` directly instead —
+        // `kind()`'s `Err` case is only ever constructed this one way in
+        // `Arena::try_add`, so this is equivalent to hitting it there.
+        let err = TryPushError {
+            head: 7,
+            kind: TryPushErrorKind::CapacityExhausted,
+        };
+
+        assert_eq!(err.kind(), TryPushErrorKind::CapacityExhausted);
+        assert_eq!(err.head, 7);
+        assert_eq!(err.to_string(), "arena capacity exhausted: no ListNodePtr slots remain");
+    }
+
+    #[test]
+    fn persistent_combinators() {
+        let mut arena = Arena::default();
+
+        let mut a = List::default();
+        a.push_front(&mut arena, 1);
+        a.push_front(&mut arena, 2);
+        a.push_front(&mut arena, 3);
+        assert_eq!(a.iter(&arena).copied().collect::<Vec<_>>(), &[3, 2, 1]);
+
+        let reversed = a.reverse(&mut arena);
+        assert_eq!(reversed.iter(&arena).copied().collect::<Vec<_>>(), &[1, 2, 3]);
+        // `a` is untouched: it's `Copy` and `reverse` only reads it.
+        assert_eq!(a.iter(&arena).copied().collect::<Vec<_>>(), &[3, 2, 1]);
+
+        let mut b = List::default();
+        b.push_front(&mut arena, 5);
+        b.push_front(&mut arena, 4);
+
+        let appended = a.append(b, &mut arena);
+        assert_eq!(
+            appended.iter(&arena).copied().collect::<Vec<_>>(),
+            &[3, 2, 1, 4, 5]
+        );
+        // `b` shares its tail with `appended`, unmodified.
+        assert_eq!(b.iter(&arena).copied().collect::<Vec<_>>(), &[4, 5]);
+
+        let mut c = List::default();
+        c.prepend_iter(&mut arena, [10, 20, 30]);
+        assert_eq!(c.iter(&arena).copied().collect::<Vec<_>>(), &[30, 20, 10]);
+
+        let from_iter = List::from_iter(&mut arena, [1, 2, 3]);
+        assert_eq!(from_iter.iter(&arena).copied().collect::<Vec<_>>(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn sync_arena_basics() {
+        let arena = SyncArena::default();
+        let mut list = List::default();
+
+        assert_eq!(list.peek_sync(&arena), None);
+
+        list.push_front_sync(&arena, 1);
+        list.push_front_sync(&arena, 2);
+        list.push_front_sync(&arena, 3);
+        assert_eq!(list.peek_sync(&arena), Some(&3));
+        assert_eq!(list.iter_sync(&arena).copied().collect::<Vec<_>>(), &[3, 2, 1]);
+
+        assert_eq!(list.pop_front_owned_sync(&arena), Some(3));
+        assert_eq!(list.pop_front_owned_sync(&arena), Some(2));
+
+        // The freed slots get reused by the next pushes.
+        list.push_front_sync(&arena, 4);
+        list.push_front_sync(&arena, 5);
+        assert_eq!(list.iter_sync(&arena).copied().collect::<Vec<_>>(), &[5, 4, 1]);
+    }
+
+    #[test]
+    fn sync_arena_concurrent_push() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let arena = Arc::new(SyncArena::default());
+        const THREADS: usize = 8;
+        const PER_THREAD: usize = 200;
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let arena = Arc::clone(&arena);
+                thread::spawn(move || {
+                    let mut list = List::default();
+                    for i in 0..PER_THREAD {
+                        list.push_front_sync(&arena, t * PER_THREAD + i);
+                    }
+                    list.iter_sync(&arena).copied().collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut all: Vec<usize> = handles.into_iter().flat_map(|h| h.join().unwrap()).collect();
+        all.sort_unstable();
+
+        let expected: Vec<usize> = (0..THREADS * PER_THREAD).collect();
+        assert_eq!(all, expected);
+    }
+
+    #[test]
+    fn sync_arena_concurrent_push_and_free() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let arena = Arc::new(SyncArena::default());
+        const THREADS: usize = 8;
+        const ITERS: usize = 2000;
+
+        // Every thread repeatedly pushes then immediately frees its own
+        // node, so the shared free list is constantly contended: one
+        // thread's `push_front_sync` popping a slot races another thread's
+        // `pop_front_owned_sync` pushing one back, on every iteration.
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let arena = Arc::clone(&arena);
+                thread::spawn(move || {
+                    let mut list = List::default();
+                    for i in 0..ITERS {
+                        let value = t * ITERS + i;
+                        list.push_front_sync(&arena, value);
+                        assert_eq!(list.pop_front_owned_sync(&arena), Some(value));
+                        assert!(list.is_empty());
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
 }